@@ -11,10 +11,11 @@ fn main() {
         .add_plugins((
             DefaultPlugins,
             local_up_plugin,
+            gravity_plugin,
             InputManagerPlugin::<Movement>::default(),
         ))
         .add_systems(Startup, setup)
-        .add_systems(Update, (movement, set_local_up))
+        .add_systems(Update, movement)
         .run();
 }
 
@@ -29,13 +30,19 @@ fn setup(
     window.cursor.grab_mode = CursorGrabMode::Locked;
     let planet_mesh = meshes.add(Sphere::new(10.0).mesh().ico(20).unwrap());
     let material = materials.add(StandardMaterial::default());
-    commands.spawn(PbrBundle {
-        mesh: planet_mesh,
-        material: material.clone(),
-        ..default()
-    });
     commands.spawn((
-        AlignToGravity,
+        PbrBundle {
+            mesh: planet_mesh,
+            material: material.clone(),
+            ..default()
+        },
+        PointGravity {
+            strength: 1.0,
+            range: 20.0,
+        },
+    ));
+    commands.spawn((
+        GravityAligned,
         AlignMode::Exponential { factor: 2.0 },
         InputManagerBundle::with_map(
             InputMap::default()
@@ -65,24 +72,6 @@ fn setup(
     });
 }
 
-#[derive(Component)]
-struct AlignToGravity;
-
-/// Aligns the player's local up with the closest planet's gravity.
-fn set_local_up(
-    players: Query<(Entity, &Transform), With<AlignToGravity>>,
-    mut commands: Commands,
-) {
-    for (e, transform) in &players {
-        let (up, distance) = Dir3::new_and_length(transform.translation).unwrap();
-        if distance < 20.0 {
-            commands.entity(e).insert(LocalUp(up));
-        } else {
-            commands.entity(e).remove::<LocalUp>();
-        }
-    }
-}
-
 #[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum Movement {
     Planar,
@@ -0,0 +1,202 @@
+//! Relative-heading reporting: report a direction relative to [`LocalUp`](crate::LocalUp) as a
+//! discrete compass or clock-face bearing, rather than a raw angle or world-space direction.
+//!
+//! Useful both for an absolute compass (bucket an avatar's forward relative to some world
+//! reference axis) and for a relative bearing (bucket a target's direction relative to an avatar's
+//! forward, e.g. "target at 2 o'clock"), which is why [`signed_bearing`] takes two directions
+//! rather than assuming one of them is always "forward". This is valuable for accessibility
+//! (spoken/on-screen navigation cues) and HUD compasses in games where "north" is relative to a
+//! curved surface rather than world axes.
+
+use bevy_math::Dir3;
+use std::f32::consts::TAU;
+use std::fmt;
+
+/// A direction bucketed into four cardinal quadrants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassQuadrant {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// A direction bucketed into eight cardinal/intercardinal octants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassOctant {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// A direction bucketed into a clock-face hour from 1 to 12, where 12 is straight ahead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockBearing(pub u8);
+
+impl fmt::Display for ClockBearing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:00", self.0)
+    }
+}
+
+/// Signed angle in `[0, TAU)` from `from` to `to`, both projected onto the plane perpendicular to
+/// `up`, increasing clockwise when viewed from outside the `up` direction (looking down along
+/// `-up`). The building block for [`compass_quadrant`], [`compass_octant`] and [`clock_bearing`].
+pub fn signed_bearing(up: Dir3, from: Dir3, to: Dir3) -> f32 {
+    let on_horizon = |v: Dir3| *v - v.dot(*up) * *up;
+    let reference = on_horizon(from).normalize_or_zero();
+    let forward = on_horizon(to).normalize_or_zero();
+    let right = reference.cross(*up);
+    forward
+        .dot(right)
+        .atan2(forward.dot(reference))
+        .rem_euclid(TAU)
+}
+
+/// Bucket `forward`'s bearing relative to `reference` (e.g. a world north axis) into one of four
+/// cardinal quadrants.
+pub fn compass_quadrant(up: Dir3, reference: Dir3, forward: Dir3) -> CompassQuadrant {
+    match bucket(signed_bearing(up, reference, forward), 4) {
+        0 => CompassQuadrant::North,
+        1 => CompassQuadrant::East,
+        2 => CompassQuadrant::South,
+        _ => CompassQuadrant::West,
+    }
+}
+
+/// Bucket `forward`'s bearing relative to `reference` (e.g. a world north axis) into one of eight
+/// cardinal/intercardinal octants.
+pub fn compass_octant(up: Dir3, reference: Dir3, forward: Dir3) -> CompassOctant {
+    match bucket(signed_bearing(up, reference, forward), 8) {
+        0 => CompassOctant::North,
+        1 => CompassOctant::NorthEast,
+        2 => CompassOctant::East,
+        3 => CompassOctant::SouthEast,
+        4 => CompassOctant::South,
+        5 => CompassOctant::SouthWest,
+        6 => CompassOctant::West,
+        _ => CompassOctant::NorthWest,
+    }
+}
+
+/// Bucket `target`'s bearing relative to `facing` (usually an avatar's forward) into a clock-face
+/// hour from 1 to 12, where 12 is straight ahead, e.g. "target at 2 o'clock".
+pub fn clock_bearing(up: Dir3, facing: Dir3, target: Dir3) -> ClockBearing {
+    let hour = bucket(signed_bearing(up, facing, target), 12);
+    ClockBearing(if hour == 0 { 12 } else { hour as u8 })
+}
+
+/// Bucket a `[0, TAU)` bearing into one of `divisions` equal slices centered on `0`.
+fn bucket(bearing: f32, divisions: u32) -> u32 {
+    let slice = TAU / divisions as f32;
+    ((bearing + slice / 2.0) / slice) as u32 % divisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Quat;
+    use std::f32::consts::PI;
+
+    const UP: Dir3 = Dir3::Y;
+    const NORTH: Dir3 = Dir3::NEG_Z;
+    // Independent of `signed_bearing`'s own cross product, so these tests can't just mirror a
+    // sign bug in the implementation back at themselves.
+    const EAST: Dir3 = Dir3::X;
+
+    // A positive angle about `UP` turns counterclockwise seen from above, i.e. lowers the bearing.
+    fn rotated(dir: Dir3, radians: f32) -> Dir3 {
+        Dir3::new(Quat::from_axis_angle(*UP, radians) * *dir).unwrap()
+    }
+
+    #[test]
+    fn signed_bearing_is_zero_facing_reference() {
+        assert!(signed_bearing(UP, NORTH, NORTH).abs() < 1e-5);
+    }
+
+    #[test]
+    fn signed_bearing_quarter_turn_is_east() {
+        let bearing = signed_bearing(UP, NORTH, EAST);
+        assert!((bearing - TAU / 4.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn signed_bearing_wraps_just_below_zero_to_just_below_tau() {
+        let bearing = signed_bearing(UP, NORTH, rotated(NORTH, 0.01));
+        assert!((bearing - (TAU - 0.01)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn bucket_rounds_half_slice_up_to_next_division() {
+        // At exactly half a slice, the boundary belongs to the next division.
+        assert_eq!(bucket(TAU / 8.0, 4), 1);
+    }
+
+    #[test]
+    fn compass_quadrant_matches_the_four_cardinal_directions() {
+        assert_eq!(compass_quadrant(UP, NORTH, NORTH), CompassQuadrant::North);
+        assert_eq!(compass_quadrant(UP, NORTH, EAST), CompassQuadrant::East);
+        assert_eq!(
+            compass_quadrant(UP, NORTH, rotated(NORTH, PI)),
+            CompassQuadrant::South
+        );
+        assert_eq!(
+            compass_quadrant(UP, NORTH, rotated(EAST, PI)),
+            CompassQuadrant::West
+        );
+    }
+
+    #[test]
+    fn compass_octant_matches_the_intercardinal_directions() {
+        let northeast = Dir3::new(*NORTH + *EAST).unwrap();
+        assert_eq!(
+            compass_octant(UP, NORTH, northeast),
+            CompassOctant::NorthEast
+        );
+    }
+
+    #[test]
+    fn compass_quadrant_wraps_around_north() {
+        // Just shy of a full turn should still read North, not West.
+        assert_eq!(
+            compass_quadrant(UP, NORTH, rotated(NORTH, 0.01)),
+            CompassQuadrant::North
+        );
+    }
+
+    #[test]
+    fn clock_bearing_straight_ahead_is_twelve() {
+        assert_eq!(clock_bearing(UP, NORTH, NORTH), ClockBearing(12));
+    }
+
+    #[test]
+    fn clock_bearing_to_the_right_is_three() {
+        assert_eq!(clock_bearing(UP, NORTH, EAST), ClockBearing(3));
+    }
+
+    #[test]
+    fn clock_bearing_displays_as_hour_colon_zero_zero() {
+        assert_eq!(ClockBearing(3).to_string(), "3:00");
+    }
+
+    #[test]
+    fn compass_quadrant_matches_transforms_own_right_convention() {
+        // `Transform::IDENTITY`'s forward is world `-Z`; its right is world `+X`. An avatar
+        // facing that forward should report its own right-hand side as East / 3 o'clock, not
+        // mirrored to West / 9 o'clock.
+        let transform = bevy_transform::components::Transform::IDENTITY;
+        assert_eq!(
+            compass_quadrant(UP, transform.forward(), transform.right()),
+            CompassQuadrant::East
+        );
+        assert_eq!(
+            clock_bearing(UP, transform.forward(), transform.right()),
+            ClockBearing(3)
+        );
+    }
+}
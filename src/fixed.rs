@@ -0,0 +1,94 @@
+//! Run alignment in [`FixedUpdate`] with render interpolation.
+//!
+//! [`local_up_plugin`](crate::local_up_plugin) runs [`align_up`]/[`sync_old_up`] in
+//! [`PostUpdate`], tying `AlignMode::Linear`/`AlignMode::Exponential` roll speeds to the variable
+//! render rate. [`local_up_plugin_fixed`] instead runs them in [`FixedUpdate`], recording the
+//! rotation before and after each fixed step and interpolating the rendered
+//! [`Transform::rotation`] between them in [`PostUpdate`] based on the fixed-step overrun
+//! fraction. This decouples alignment from framerate and lets the crate coexist cleanly with
+//! fixed-timestep physics-driven movement.
+
+use bevy_app::{App, FixedUpdate, PostUpdate};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::With,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query, Res},
+};
+use bevy_math::Quat;
+use bevy_time::{Fixed, Time};
+use bevy_transform::{components::Transform, TransformSystem::TransformPropagate};
+
+use crate::{align_up, sync_old_up, AlignUp, LocalUp};
+
+/// Like [`crate::local_up_plugin`], but runs [`align_up`]/[`sync_old_up`] in [`FixedUpdate`] and
+/// interpolates the rendered rotation between fixed steps in [`PostUpdate`].
+pub fn local_up_plugin_fixed(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        (
+            restore_align_rotation,
+            (align_up, sync_old_up).chain(),
+            capture_align_rotation,
+        )
+            .chain()
+            .in_set(AlignUp),
+    );
+    app.add_systems(
+        PostUpdate,
+        interpolate_align_rotation.before(TransformPropagate),
+    );
+}
+
+/// True, fixed-step rotation of an avatar aligned by [`local_up_plugin_fixed`]. The rendered
+/// [`Transform::rotation`] is a slerp between `previous` and `current` written by
+/// [`interpolate_align_rotation`], so it must be restored to `current` before each fixed step runs
+/// alignment again.
+#[derive(Component, Debug, Clone, Copy)]
+struct AlignRotation {
+    previous: Quat,
+    current: Quat,
+}
+
+/// Restore each avatar's [`Transform::rotation`] to its true last-fixed-step value (undoing the
+/// previous frame's render interpolation) before [`align_up`] runs again, and shift `current` into
+/// `previous` so this step's interpolation has a starting point.
+fn restore_align_rotation(
+    mut avatars: Query<(Entity, &mut Transform, Option<&mut AlignRotation>), With<LocalUp>>,
+    mut commands: Commands,
+) {
+    for (e, mut transform, rotation) in &mut avatars {
+        match rotation {
+            Some(mut rotation) => {
+                rotation.previous = rotation.current;
+                transform.rotation = rotation.current;
+            }
+            None => {
+                commands.entity(e).insert(AlignRotation {
+                    previous: transform.rotation,
+                    current: transform.rotation,
+                });
+            }
+        }
+    }
+}
+
+/// Record the rotation [`align_up`] just produced as this fixed step's `current` value.
+fn capture_align_rotation(mut avatars: Query<(&Transform, &mut AlignRotation), With<LocalUp>>) {
+    for (transform, mut rotation) in &mut avatars {
+        rotation.current = transform.rotation;
+    }
+}
+
+/// Blend each avatar's rendered [`Transform::rotation`] between its last two fixed-step rotations,
+/// by how far the render frame has overrun the last completed fixed step.
+fn interpolate_align_rotation(
+    mut avatars: Query<(&AlignRotation, &mut Transform), With<LocalUp>>,
+    time: Res<Time<Fixed>>,
+) {
+    let t = time.overstep_fraction();
+    for (rotation, mut transform) in &mut avatars {
+        transform.rotation = rotation.previous.slerp(rotation.current, t);
+    }
+}
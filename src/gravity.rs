@@ -0,0 +1,141 @@
+//! Multi-body gravity field subsystem that drives [`LocalUp`](crate::LocalUp) automatically.
+//!
+//! Instead of hand-writing a system that measures distance to a single planet, register gravity
+//! sources as components and mark avatars with [`GravityAligned`]; [`compute_gravity_up`] sums the
+//! weighted pull of every source in range and writes the result into [`LocalUp`](crate::LocalUp).
+
+use bevy_app::{App, PostUpdate};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query},
+};
+use bevy_math::{Dir3, Vec3};
+use bevy_transform::components::GlobalTransform;
+
+use crate::{AlignUp, LocalUp};
+
+/// Registers [`compute_gravity_up`] so it runs before [`AlignUp`], in addition to
+/// [`crate::local_up_plugin`].
+pub fn gravity_plugin(app: &mut App) {
+    app.add_systems(PostUpdate, compute_gravity_up.before(AlignUp));
+}
+
+/// Marks an avatar's [`LocalUp`] as driven by the gravity sources in the world, rather than set by
+/// hand. Combine with [`PointGravity`], [`UniformGravity`], [`PlanarGravity`] and [`DiskGravity`]
+/// sources to build a multi-planet or open-space game.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct GravityAligned;
+
+/// A point gravity source, such as a planet. Pulls [`GravityAligned`] avatars towards its
+/// [`GlobalTransform`] origin, linearly falling off to zero at `range`.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PointGravity {
+    /// Pull strength at zero distance.
+    pub strength: f32,
+    /// Distance at which this source's pull falls off to zero.
+    pub range: f32,
+}
+
+/// A constant gravity direction affecting every [`GravityAligned`] avatar regardless of position.
+/// Useful for a single flat world, or mixed in with other sources to bias blending near their
+/// boundary.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UniformGravity {
+    /// Up direction this source produces; avatars are pulled the opposite way.
+    pub up: Dir3,
+    /// Pull strength.
+    pub strength: f32,
+}
+
+/// An infinite-plane gravity source through the entity's origin, perpendicular to `up` (in the
+/// entity's local space). Avatars on the `up` side are pulled towards the plane and get `up` as
+/// their up direction. Falls off to zero at `range` along the plane's normal.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PlanarGravity {
+    /// Up direction in the source entity's local space.
+    pub up: Dir3,
+    /// Pull strength at the plane's surface.
+    pub strength: f32,
+    /// Distance from the plane at which the pull falls off to zero.
+    pub range: f32,
+}
+
+/// A finite disk-shaped gravity source centered on the entity's origin, perpendicular to `up` (in
+/// the entity's local space). Avatars on the `up` side are pulled towards the disk and get `up` as
+/// their up direction. Falls off to zero at `range` along the disk's normal; beyond `radius` from
+/// the center along the disk's plane there is no pull at all.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DiskGravity {
+    /// Up direction in the source entity's local space.
+    pub up: Dir3,
+    /// Radius of the disk.
+    pub radius: f32,
+    /// Pull strength at the disk's surface.
+    pub strength: f32,
+    /// Distance from the disk at which the pull falls off to zero.
+    pub range: f32,
+}
+
+/// Linear falloff from `strength` at zero distance to `0.0` at `range`, clamped to non-negative.
+/// Applying this falloff to point, planar and disk sources along their range is what makes
+/// transitioning between bodies blend smoothly instead of snapping at a hard range boundary.
+/// [`UniformGravity`] does not fall off, and a disk's `radius` edge is a hard cut-off.
+fn falloff(strength: f32, distance: f32, range: f32) -> f32 {
+    (strength * (1.0 - distance / range)).max(0.0)
+}
+
+/// Compute each [`GravityAligned`] avatar's net up direction as the weighted sum of every gravity
+/// source in range, and write it into [`LocalUp`]. Removes [`LocalUp`] when no source is in range.
+pub fn compute_gravity_up(
+    avatars: Query<(Entity, &GlobalTransform), bevy_ecs::query::With<GravityAligned>>,
+    points: Query<(&GlobalTransform, &PointGravity)>,
+    uniforms: Query<&UniformGravity>,
+    planars: Query<(&GlobalTransform, &PlanarGravity)>,
+    disks: Query<(&GlobalTransform, &DiskGravity)>,
+    mut commands: Commands,
+) {
+    for (avatar, avatar_transform) in &avatars {
+        let position = avatar_transform.translation();
+        let mut pull = Vec3::ZERO;
+
+        for (source, point) in &points {
+            let offset = position - source.translation();
+            let Ok((dir, distance)) = Dir3::new_and_length(offset) else {
+                continue;
+            };
+            if distance < point.range {
+                pull += *dir * falloff(point.strength, distance, point.range);
+            }
+        }
+
+        for uniform in &uniforms {
+            pull += *uniform.up * uniform.strength;
+        }
+
+        for (source, planar) in &planars {
+            let up = source.compute_transform().rotation * planar.up;
+            let distance = (position - source.translation()).dot(*up);
+            if distance >= 0.0 && distance < planar.range {
+                pull += *up * falloff(planar.strength, distance, planar.range);
+            }
+        }
+
+        for (source, disk) in &disks {
+            let up = source.compute_transform().rotation * disk.up;
+            let offset = position - source.translation();
+            let distance = offset.dot(*up);
+            let radial = (offset - distance * *up).length();
+            if distance >= 0.0 && distance < disk.range && radial < disk.radius {
+                pull += *up * falloff(disk.strength, distance, disk.range);
+            }
+        }
+
+        if let Ok(up) = Dir3::new(pull) {
+            commands.entity(avatar).insert(LocalUp(up));
+        } else {
+            commands.entity(avatar).remove::<LocalUp>();
+        }
+    }
+}
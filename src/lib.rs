@@ -6,12 +6,50 @@ use bevy_ecs::{
     schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
     system::{Commands, Query, Res},
 };
-use bevy_math::{Dir3, Mat3, Quat};
+use bevy_math::{Dir3, Mat3, Quat, Vec3};
 use bevy_time::Time;
 use bevy_transform::{components::Transform, TransformSystem::TransformPropagate};
+use std::f32::consts::{PI, TAU};
+
+mod compass;
+mod fixed;
+mod gravity;
+mod physics;
+mod surface;
+mod view;
+
+pub use compass::{
+    clock_bearing, compass_octant, compass_quadrant, signed_bearing, ClockBearing, CompassOctant,
+    CompassQuadrant,
+};
+pub use fixed::local_up_plugin_fixed;
+pub use gravity::{
+    compute_gravity_up, gravity_plugin, DiskGravity, GravityAligned, PlanarGravity, PointGravity,
+    UniformGravity,
+};
+pub use physics::AlignVelocity;
+#[cfg(feature = "avian")]
+pub use physics::avian_plugin;
+#[cfg(feature = "rapier")]
+pub use physics::rapier_plugin;
+pub use surface::SurfaceUp;
+#[cfg(feature = "avian")]
+pub use surface::surface_up_avian_plugin;
+#[cfg(feature = "rapier")]
+pub use surface::surface_up_rapier_plugin;
+pub use view::{view_follower_plugin, ViewFollower};
 
 pub mod prelude {
-    pub use super::{local_up_plugin, AlignMode, LocalUp};
+    pub use super::{
+        clock_bearing, compass_octant, compass_quadrant, gravity_plugin, local_up_plugin,
+        local_up_plugin_fixed, signed_bearing, view_follower_plugin, AlignMode, AlignOutput,
+        ClockBearing, CompassOctant, CompassQuadrant, DiskGravity, GravityAligned, LocalUp,
+        PlanarGravity, PointGravity, SmoothDampVelocity, SurfaceUp, UniformGravity, ViewFollower,
+    };
+    #[cfg(feature = "avian")]
+    pub use super::{avian_plugin, surface_up_avian_plugin};
+    #[cfg(feature = "rapier")]
+    pub use super::{rapier_plugin, surface_up_rapier_plugin};
 }
 
 pub fn local_up_plugin(app: &mut App) {
@@ -63,6 +101,48 @@ pub enum AlignMode {
         /// as the player orientation reaches its target.
         factor: f32,
     },
+    /// Align to local up with a critically damped spring, carrying angular velocity through
+    /// [`LocalUp`] changes in a [`SmoothDampVelocity`] companion component. Unlike
+    /// [`AlignMode::Exponential`], this is stable at any timestep: `factor * dt` exceeding `1.0` at
+    /// a low frame rate cannot cause it to overshoot.
+    SmoothDamp {
+        /// Approximate time to close the remaining gap to target; smaller is snappier.
+        smooth_time: f32,
+    },
+}
+
+/// Angular velocity carried between frames by [`AlignMode::SmoothDamp`]. Maintained automatically
+/// by [`align_up`]; reading it lets other systems react to how fast an avatar is currently rolling.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct SmoothDampVelocity(pub Vec3);
+
+/// Determines where [`align_up`] writes its alignment result. If this component is not present on
+/// an entity with a [`LocalUp`] component, [`AlignOutput::Transform`] is assumed.
+#[derive(Component, Debug, Clone, Copy)]
+pub enum AlignOutput {
+    /// Write the aligned rotation directly to [`Transform::rotation`] (the default). Fights any
+    /// physics engine that owns the body's orientation, so prefer [`AlignOutput::AngularVelocity`]
+    /// for a dynamic or kinematic character controller.
+    Transform,
+    /// Leave [`Transform::rotation`] untouched and instead write the alignment error into
+    /// [`AlignVelocity`] as an angular velocity, for a physics engine to integrate. `damping` is
+    /// a gain: the output is the shortest-arc error times `damping`, so `1.0` turns at a rate
+    /// equal to the remaining error per second and higher values correct faster.
+    AngularVelocity {
+        /// Scales the output angular velocity; see above.
+        damping: f32,
+    },
+}
+
+/// Shortest-arc rotation from `from` to `to`, as an axis-angle vector whose direction is the
+/// rotation axis and whose length is the rotation angle in radians.
+fn rotation_error(from: Quat, to: Quat) -> Vec3 {
+    let delta = to * from.inverse();
+    let (axis, mut angle) = delta.to_axis_angle();
+    if angle > PI {
+        angle -= TAU;
+    }
+    axis * angle
 }
 
 /// Sync an entity's [`OldUp`] with its [`LocalUp`]. If the entity's [`LocalUp`] has been removed,
@@ -86,19 +166,26 @@ pub fn sync_old_up(avatars: Query<(Entity, AnyOf<(&LocalUp, &OldUp)>)>, mut comm
 /// frame (tracked by means of [`OldUp`]), its transform will be adjusted to keep its view elevation
 /// relative to the horizon unchanged. If the avatar did not have a [`LocalUp`] last frame, it will
 /// be rolled to align with the new frame, keeping the look direction unchanged. The rate at which
-/// this roll is performed is determined by the entity's [`AlignMode`].
+/// this roll is performed is determined by the entity's [`AlignMode`]. Where the result is written
+/// is determined by the entity's [`AlignOutput`]: directly to [`Transform::rotation`] by default,
+/// or as an [`AlignVelocity`] for a physics engine to integrate.
 pub fn align_up(
     mut avatars: Query<(
         Entity,
         &LocalUp,
         Option<&OldUp>,
         Option<&AlignMode>,
+        Option<&AlignOutput>,
+        Option<&SmoothDampVelocity>,
         &mut Transform,
     )>,
     time: Res<Time>,
     mut commands: Commands,
 ) {
-    for (e, local_up, old_up, align_mode, mut transform) in &mut avatars {
+    for (e, local_up, old_up, align_mode, align_output, smooth_damp_velocity, mut transform) in
+        &mut avatars
+    {
+        let original_rotation = transform.rotation;
         if let Some(old_up) = old_up {
             let rotation = Quat::from_rotation_arc(*old_up.0, *local_up.0);
             transform.rotation = rotation * transform.rotation;
@@ -110,22 +197,44 @@ pub fn align_up(
         let new_up = new_right.cross(*transform.forward());
         let target_rotation =
             Quat::from_mat3(&Mat3::from_cols(new_right, new_up, *transform.local_z()));
-        transform.rotation = match align_mode {
-            None => target_rotation,
-            Some(&AlignMode::Linear { rate }) => {
-                // Step rotation towards target_rotation by at most rate * dt radians
-                let angle_to_target = transform.rotation.angle_between(target_rotation);
-                if angle_to_target == 0.0 {
-                    target_rotation
-                } else {
-                    let delta_angle = angle_to_target.min(rate * time.delta_seconds());
-                    let lerp_factor = delta_angle / angle_to_target;
-                    transform.rotation.slerp(target_rotation, lerp_factor)
-                }
+        match align_output {
+            None | Some(AlignOutput::Transform) => {
+                transform.rotation = match align_mode {
+                    None => target_rotation,
+                    Some(&AlignMode::Linear { rate }) => {
+                        // Step rotation towards target_rotation by at most rate * dt radians
+                        let angle_to_target = transform.rotation.angle_between(target_rotation);
+                        if angle_to_target == 0.0 {
+                            target_rotation
+                        } else {
+                            let delta_angle = angle_to_target.min(rate * time.delta_seconds());
+                            let lerp_factor = delta_angle / angle_to_target;
+                            transform.rotation.slerp(target_rotation, lerp_factor)
+                        }
+                    }
+                    Some(&AlignMode::Exponential { factor }) => transform
+                        .rotation
+                        .slerp(target_rotation, factor * time.delta_seconds()),
+                    Some(&AlignMode::SmoothDamp { smooth_time }) => {
+                        let dt = time.delta_seconds();
+                        let omega = 2.0 / smooth_time;
+                        let x = omega * dt;
+                        let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+                        let vel = smooth_damp_velocity.map_or(Vec3::ZERO, |v| v.0);
+                        let err = rotation_error(transform.rotation, target_rotation);
+                        let temp = (vel + omega * err) * dt;
+                        let new_vel = (vel - omega * temp) * exp;
+                        let new_err = (err + temp) * exp;
+                        commands.entity(e).insert(SmoothDampVelocity(new_vel));
+                        Quat::from_scaled_axis(err - new_err) * transform.rotation
+                    }
+                };
             }
-            Some(&AlignMode::Exponential { factor }) => transform
-                .rotation
-                .slerp(target_rotation, factor * time.delta_seconds()),
-        };
+            Some(&AlignOutput::AngularVelocity { damping }) => {
+                let error = rotation_error(original_rotation, target_rotation);
+                transform.rotation = original_rotation;
+                commands.entity(e).insert(AlignVelocity(error * damping));
+            }
+        }
     }
 }
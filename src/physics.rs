@@ -0,0 +1,66 @@
+//! Optional physics-engine integration for [`AlignOutput::AngularVelocity`](crate::AlignOutput).
+//!
+//! [`crate::align_up`] writes the alignment error into [`AlignVelocity`] instead of the entity's
+//! [`Transform`](bevy_transform::components::Transform) when an avatar's output mode calls for it,
+//! leaving the physics engine in control of the body's orientation (avoiding the jitter that comes
+//! from fighting a rigid-body solver that owns the transform). The `rapier` and `avian` features
+//! each add a system that copies [`AlignVelocity`] into that engine's own angular velocity
+//! component.
+
+use bevy_ecs::component::Component;
+use bevy_math::Vec3;
+
+/// Angular velocity computed by [`crate::align_up`] for an avatar whose
+/// [`AlignOutput`](crate::AlignOutput) is [`AlignOutput::AngularVelocity`](crate::AlignOutput),
+/// in radians per second. Enable the `rapier` or `avian` feature to have this copied into that
+/// engine's velocity component every frame, or read it yourself to drive another integration.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct AlignVelocity(pub Vec3);
+
+#[cfg(feature = "rapier")]
+mod rapier {
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::{schedule::IntoSystemConfigs, system::Query};
+    use bevy_rapier3d::dynamics::Velocity;
+
+    use super::AlignVelocity;
+    use crate::AlignUp;
+
+    /// Adds a system that copies each avatar's [`AlignVelocity`] into its rapier [`Velocity`],
+    /// alongside [`crate::local_up_plugin`].
+    pub fn rapier_plugin(app: &mut App) {
+        app.add_systems(PostUpdate, sync_rapier_velocity.in_set(AlignUp));
+    }
+
+    fn sync_rapier_velocity(mut bodies: Query<(&AlignVelocity, &mut Velocity)>) {
+        for (align, mut velocity) in &mut bodies {
+            velocity.angvel = align.0;
+        }
+    }
+}
+#[cfg(feature = "rapier")]
+pub use rapier::rapier_plugin;
+
+#[cfg(feature = "avian")]
+mod avian {
+    use avian3d::prelude::AngularVelocity;
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::{schedule::IntoSystemConfigs, system::Query};
+
+    use super::AlignVelocity;
+    use crate::AlignUp;
+
+    /// Adds a system that copies each avatar's [`AlignVelocity`] into its avian
+    /// [`AngularVelocity`], alongside [`crate::local_up_plugin`].
+    pub fn avian_plugin(app: &mut App) {
+        app.add_systems(PostUpdate, sync_avian_velocity.in_set(AlignUp));
+    }
+
+    fn sync_avian_velocity(mut bodies: Query<(&AlignVelocity, &mut AngularVelocity)>) {
+        for (align, mut velocity) in &mut bodies {
+            velocity.0 = align.0;
+        }
+    }
+}
+#[cfg(feature = "avian")]
+pub use avian::avian_plugin;
@@ -0,0 +1,135 @@
+//! Derive [`LocalUp`] from nearby geometry instead of requiring the user to hand in a [`Dir3`].
+//!
+//! Add [`SurfaceUp`] to an avatar and enable the `rapier` or `avian` feature; a ray is cast
+//! downward along the current up direction and the contact normal becomes the new up, reusing the
+//! existing [`OldUp`](crate::OldUp)/[`AlignMode`](crate::AlignMode) machinery to keep the
+//! transition smooth. This lets avatars walk over arbitrary terrain and automatically roll to
+//! match the surface, including slopes, caves and the inside of a hollow body.
+
+use bevy_ecs::component::Component;
+use bevy_math::Dir3;
+
+/// Drives an avatar's [`LocalUp`] from the surface normal beneath it, rather than a hand-supplied
+/// direction. Requires the `rapier` or `avian` feature to actually perform the cast.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SurfaceUp {
+    /// Maximum distance to cast downward, against the current up direction, before giving up.
+    pub max_distance: f32,
+    /// Up direction to use when the avatar has no [`LocalUp`] yet and nothing is hit.
+    pub fallback: Dir3,
+    /// Only colliders in these layers count as walkable geometry; `None` hits everything.
+    pub layers: Option<u32>,
+}
+
+#[cfg(feature = "rapier")]
+mod rapier {
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::{
+        entity::Entity,
+        schedule::IntoSystemConfigs,
+        system::{Commands, Query, Res},
+    };
+    use bevy_math::Dir3;
+    use bevy_rapier3d::{
+        geometry::{CollisionGroups, Group},
+        pipeline::QueryFilter,
+        plugin::RapierContext,
+    };
+    use bevy_transform::components::GlobalTransform;
+
+    use super::SurfaceUp;
+    use crate::{AlignUp, LocalUp};
+
+    /// Adds [`compute_surface_up`] so it runs before [`AlignUp`], alongside
+    /// [`crate::local_up_plugin`].
+    pub fn surface_up_rapier_plugin(app: &mut App) {
+        app.add_systems(PostUpdate, compute_surface_up.before(AlignUp));
+    }
+
+    fn compute_surface_up(
+        avatars: Query<(Entity, &GlobalTransform, &SurfaceUp, Option<&LocalUp>)>,
+        rapier_context: Res<RapierContext>,
+        mut commands: Commands,
+    ) {
+        for (e, transform, surface, local_up) in &avatars {
+            let up = local_up.map_or(surface.fallback, |up| up.0);
+            let filter = match surface.layers {
+                Some(layers) => {
+                    QueryFilter::new().groups(CollisionGroups::new(
+                        Group::ALL,
+                        Group::from_bits_truncate(layers),
+                    ))
+                }
+                None => QueryFilter::new(),
+            };
+            let hit = rapier_context.cast_ray_and_get_normal(
+                transform.translation(),
+                -*up,
+                surface.max_distance,
+                true,
+                filter,
+            );
+            if let Some((_, hit)) = hit {
+                if let Ok(new_up) = Dir3::new(hit.normal) {
+                    commands.entity(e).insert(LocalUp(new_up));
+                }
+            } else if local_up.is_none() {
+                commands.entity(e).insert(LocalUp(surface.fallback));
+            }
+        }
+    }
+}
+#[cfg(feature = "rapier")]
+pub use rapier::surface_up_rapier_plugin;
+
+#[cfg(feature = "avian")]
+mod avian {
+    use avian3d::prelude::{LayerMask, SpatialQuery, SpatialQueryFilter};
+    use bevy_app::{App, PostUpdate};
+    use bevy_ecs::{
+        entity::Entity,
+        schedule::IntoSystemConfigs,
+        system::{Commands, Query},
+    };
+    use bevy_math::Dir3;
+    use bevy_transform::components::GlobalTransform;
+
+    use super::SurfaceUp;
+    use crate::{AlignUp, LocalUp};
+
+    /// Adds [`compute_surface_up`] so it runs before [`AlignUp`], alongside
+    /// [`crate::local_up_plugin`].
+    pub fn surface_up_avian_plugin(app: &mut App) {
+        app.add_systems(PostUpdate, compute_surface_up.before(AlignUp));
+    }
+
+    fn compute_surface_up(
+        avatars: Query<(Entity, &GlobalTransform, &SurfaceUp, Option<&LocalUp>)>,
+        spatial_query: SpatialQuery,
+        mut commands: Commands,
+    ) {
+        for (e, transform, surface, local_up) in &avatars {
+            let up = local_up.map_or(surface.fallback, |up| up.0);
+            let filter = match surface.layers {
+                Some(layers) => SpatialQueryFilter::from_mask(LayerMask(layers)),
+                None => SpatialQueryFilter::default(),
+            };
+            let hit = spatial_query.cast_ray(
+                transform.translation(),
+                -up,
+                surface.max_distance,
+                true,
+                filter,
+            );
+            if let Some(hit) = hit {
+                if let Ok(new_up) = Dir3::new(hit.normal) {
+                    commands.entity(e).insert(LocalUp(new_up));
+                }
+            } else if local_up.is_none() {
+                commands.entity(e).insert(LocalUp(surface.fallback));
+            }
+        }
+    }
+}
+#[cfg(feature = "avian")]
+pub use avian::surface_up_avian_plugin;
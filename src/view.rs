@@ -0,0 +1,109 @@
+//! Delayed/lerped view decoupling from the aligning body.
+//!
+//! A child "view" entity (e.g. a camera or held-item rig) often shouldn't snap rigidly with its
+//! parent body as [`LocalUp`] changes. [`ViewFollower`] lets it instead follow with a short delay:
+//! the body re-rolls immediately via [`align_up`](crate::align_up), while the view stays rigid for
+//! `delay` seconds and then eases into the new attitude at `catch_up_rate`. A realignment is
+//! detected by comparing the parent's current [`LocalUp`] direction against the value observed
+//! last frame, independently of the body's [`AlignMode`](crate::AlignMode).
+
+use bevy_app::{App, PostUpdate};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::Without,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query, Res},
+};
+use bevy_math::Dir3;
+use bevy_time::Time;
+use bevy_transform::{components::Transform, TransformSystem::TransformPropagate};
+
+use crate::{AlignUp, LocalUp};
+
+/// Adds [`follow_view`], running after [`AlignUp`] (so it sees this frame's realignment) and
+/// before transform propagation.
+pub fn view_follower_plugin(app: &mut App) {
+    app.add_systems(
+        PostUpdate,
+        follow_view.after(AlignUp).before(TransformPropagate),
+    );
+}
+
+/// Tracks `parent`'s orientation with a short delay instead of snapping to it every frame. Attach
+/// to a view entity (camera, held-item rig) whose parent body has [`LocalUp`](crate::LocalUp).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ViewFollower {
+    /// Entity whose [`Transform::rotation`] this view tracks.
+    pub parent: Entity,
+    /// Seconds to stay snapped to the parent's orientation after it realigns, before easing into
+    /// the new attitude.
+    pub delay: f32,
+    /// Ease-in rate once `delay` has elapsed, in the same units as
+    /// [`AlignMode::Exponential`](crate::AlignMode::Exponential)'s `factor`.
+    pub catch_up_rate: f32,
+}
+
+/// Time remaining before a [`ViewFollower`] starts easing towards its parent's orientation.
+/// Maintained automatically by [`follow_view`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+struct ViewFollowerDelay(f32);
+
+/// The parent's [`LocalUp`] direction as observed last frame, so [`follow_view`] can tell whether
+/// it just realigned. Maintained automatically by [`follow_view`].
+#[derive(Component, Debug, Clone, Copy)]
+struct ViewFollowerUp(Dir3);
+
+/// Snap each [`ViewFollower`] to its parent's orientation for `delay` seconds after the parent's
+/// [`LocalUp`] direction actually changes (i.e. it realigned), then ease towards it at
+/// `catch_up_rate`.
+fn follow_view(
+    mut views: Query<(
+        Entity,
+        &ViewFollower,
+        &mut Transform,
+        Option<&mut ViewFollowerDelay>,
+        Option<&mut ViewFollowerUp>,
+    )>,
+    parents: Query<(&Transform, &LocalUp), Without<ViewFollower>>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for (e, follower, mut transform, delay, tracked_up) in &mut views {
+        let Ok((parent_transform, local_up)) = parents.get(follower.parent) else {
+            continue;
+        };
+        let realigned = match tracked_up {
+            Some(mut tracked_up) => {
+                let realigned = local_up.0.dot(*tracked_up.0) < 1.0 - 1e-5;
+                tracked_up.0 = local_up.0;
+                realigned
+            }
+            None => {
+                commands.entity(e).insert(ViewFollowerUp(local_up.0));
+                false
+            }
+        };
+        let remaining = match delay {
+            Some(mut delay) => {
+                delay.0 = if realigned {
+                    follower.delay
+                } else {
+                    (delay.0 - time.delta_seconds()).max(0.0)
+                };
+                delay.0
+            }
+            None => {
+                let remaining = if realigned { follower.delay } else { 0.0 };
+                commands.entity(e).insert(ViewFollowerDelay(remaining));
+                remaining
+            }
+        };
+        if remaining > 0.0 {
+            transform.rotation = parent_transform.rotation;
+        } else {
+            let factor = (follower.catch_up_rate * time.delta_seconds()).min(1.0);
+            transform.rotation = transform.rotation.slerp(parent_transform.rotation, factor);
+        }
+    }
+}